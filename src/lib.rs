@@ -39,7 +39,8 @@ ensure(|| {
 This crate also provides `Present<T>` and `Absent<T>` wrapper types to mark ensured external states in type system.
 
 If `T` implements `Ensure<Present<T>>` and `Ensure<Absetnt<T>>` it automatically implements `Existential<T>` trait
-that provides methods `ensure_present()` and `ensure_absent()`.
+that provides methods `ensure_present()` and `ensure_absent()`, as well as `check_existence()` which cheaply
+reports a `Present`, `Absent` or `Unknown` `Existence<T>` without ever calling `meet()`.
 
 See tests for example usage.
 */
@@ -57,6 +58,74 @@ pub enum CheckEnsureResult<M, A> {
     EnsureAction(A),
 }
 
+impl<M, A> CheckEnsureResult<M, A> {
+    /// `true` if target state is already `Met`
+    pub fn is_met(&self) -> bool {
+        matches!(self, CheckEnsureResult::Met(_))
+    }
+
+    /// `true` if target state requires an `EnsureAction` to be met
+    pub fn is_action(&self) -> bool {
+        matches!(self, CheckEnsureResult::EnsureAction(_))
+    }
+
+    /// Convert to `Option` discarding `EnsureAction`
+    pub fn met(self) -> Option<M> {
+        match self {
+            CheckEnsureResult::Met(met) => Some(met),
+            CheckEnsureResult::EnsureAction(_) => None,
+        }
+    }
+
+    /// Convert to `Option` discarding `Met`
+    pub fn action(self) -> Option<A> {
+        match self {
+            CheckEnsureResult::Met(_) => None,
+            CheckEnsureResult::EnsureAction(action) => Some(action),
+        }
+    }
+
+    /// Map the `Met` variant, leaving `EnsureAction` untouched
+    pub fn map_met<N>(self, f: impl FnOnce(M) -> N) -> CheckEnsureResult<N, A> {
+        match self {
+            CheckEnsureResult::Met(met) => CheckEnsureResult::Met(f(met)),
+            CheckEnsureResult::EnsureAction(action) => CheckEnsureResult::EnsureAction(action),
+        }
+    }
+
+    /// Map the `EnsureAction` variant, leaving `Met` untouched
+    pub fn map_action<B>(self, f: impl FnOnce(A) -> B) -> CheckEnsureResult<M, B> {
+        match self {
+            CheckEnsureResult::Met(met) => CheckEnsureResult::Met(met),
+            CheckEnsureResult::EnsureAction(action) => CheckEnsureResult::EnsureAction(f(action)),
+        }
+    }
+
+    /// Collapse `EnsureAction` into a `Met` value, leaving `Met` untouched
+    pub fn met_or_else(self, f: impl FnOnce(A) -> M) -> M {
+        match self {
+            CheckEnsureResult::Met(met) => met,
+            CheckEnsureResult::EnsureAction(action) => f(action),
+        }
+    }
+
+    /// Convert from `&CheckEnsureResult<M, A>` to `CheckEnsureResult<&M, &A>`
+    pub fn as_ref(&self) -> CheckEnsureResult<&M, &A> {
+        match self {
+            CheckEnsureResult::Met(met) => CheckEnsureResult::Met(met),
+            CheckEnsureResult::EnsureAction(action) => CheckEnsureResult::EnsureAction(action),
+        }
+    }
+
+    /// Convert from `&mut CheckEnsureResult<M, A>` to `CheckEnsureResult<&mut M, &mut A>`
+    pub fn as_mut(&mut self) -> CheckEnsureResult<&mut M, &mut A> {
+        match self {
+            CheckEnsureResult::Met(met) => CheckEnsureResult::Met(met),
+            CheckEnsureResult::EnsureAction(action) => CheckEnsureResult::EnsureAction(action),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct VerificationError;
 
@@ -69,6 +138,74 @@ impl fmt::Display for VerificationError {
 /// Error raised if `Ensure::ensure_verify()` failed verification
 impl Error for VerificationError {}
 
+/// Result of `Ensure::ensure_report()` telling apart a target state that was already satisfied
+/// from one that had to be brought about by running `meet()`.
+#[derive(Debug)]
+pub enum Ensured<T> {
+    AlreadyMet(T),
+    Ensured(T),
+}
+
+impl<T> Ensured<T> {
+    /// Discard whether the target state was already met and return the value.
+    pub fn into_inner(self) -> T {
+        match self {
+            Ensured::AlreadyMet(value) => value,
+            Ensured::Ensured(value) => value,
+        }
+    }
+
+    /// `true` if `meet()` had to run to bring the target state about.
+    pub fn was_changed(&self) -> bool {
+        match self {
+            Ensured::AlreadyMet(_) => false,
+            Ensured::Ensured(_) => true,
+        }
+    }
+}
+
+impl<T> Deref for Ensured<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Ensured::AlreadyMet(value) => value,
+            Ensured::Ensured(value) => value,
+        }
+    }
+}
+
+impl<T> PartialEq for Ensured<T> where T: PartialEq {
+    fn eq(&self, other: &Self) -> bool {
+        self.deref() == other.deref() && self.was_changed() == other.was_changed()
+    }
+}
+
+/// Result of `Ensure::plan()` separating discovery of what would change from actually changing it.
+///
+/// `Nothing` means the target state is already met, `Pending` carries the `EnsureAction` that
+/// would be run by `apply()` to reach it.
+#[derive(Debug)]
+pub enum Plan<T, A> {
+    Nothing(T),
+    Pending(A),
+}
+
+impl<T, A> Plan<T, A> {
+    /// `true` if reaching the target state requires running an `EnsureAction`
+    pub fn is_pending(&self) -> bool {
+        matches!(self, Plan::Pending(_))
+    }
+
+    /// Run `meet()` on the pending `EnsureAction`, if any, to actually reach the target state
+    pub fn apply(self) -> Result<T, A::Error> where A: Meet<Met = T> {
+        match self {
+            Plan::Nothing(met) => Ok(met),
+            Plan::Pending(action) => action.meet(),
+        }
+    }
+}
+
 /// Function that can be used to bring object in its target state
 pub trait Meet {
     type Met;
@@ -92,6 +229,22 @@ pub trait Ensure<T>: Sized {
         }
     }
 
+    /// Ensure target state like `ensure()` but report whether it was already met or had to be brought about by `meet()`
+    fn ensure_report(self) -> Result<Ensured<T>, <Self::EnsureAction as Meet>::Error> {
+        match self.check_ensure()? {
+            CheckEnsureResult::Met(met) => Ok(Ensured::AlreadyMet(met)),
+            CheckEnsureResult::EnsureAction(meet) => Ok(Ensured::Ensured(meet.meet()?)),
+        }
+    }
+
+    /// Check target state without bringing it about, returning a `Plan` that can be inspected and later `apply()`-ed
+    fn plan(self) -> Result<Plan<T, Self::EnsureAction>, <Self::EnsureAction as Meet>::Error> {
+        match self.check_ensure()? {
+            CheckEnsureResult::Met(met) => Ok(Plan::Nothing(met)),
+            CheckEnsureResult::EnsureAction(action) => Ok(Plan::Pending(action)),
+        }
+    }
+
     /// Ensure target state and then verify that `EnsureAction` actually brought external state to target state by calling `check_ensure()` on clone of `self`
     fn ensure_verify(self) -> Result<T, <Self::EnsureAction as Meet>::Error> where Self: Clone, <Self::EnsureAction as Meet>::Error: From<VerificationError> {
         let verify = self.clone();
@@ -194,6 +347,233 @@ impl<T> PartialOrd for Absent<T> where T: PartialOrd {
     }
 }
 
+/// Ensures `A` first, then `B`, short-circuiting on error via `meet()`'s `?`.
+///
+/// `B` is only checked once `A` is actually at its target state: if `A` is already `Met` it is
+/// safe to check `B` right away, but if `A` needs an `EnsureAction`, `B` is held unevaluated and
+/// only `check_ensure()`-d (via `ensure()`) after that action's `meet()` has run. This is what
+/// makes `Sequence` prerequisite-aware: "ensure the directory exists, and only then ensure the
+/// file inside it" would otherwise check the file against a directory that does not exist yet.
+/// Produces a `(A::Met, B::Met)` tuple once both are brought to their target state.
+pub struct Sequence<A, B>(pub A, pub B);
+
+/// `EnsureAction` for `Sequence<A, B>`.
+#[derive(Debug)]
+pub enum SequenceAction<TA, AA, B, BA> {
+    /// `A` was already `Met`, `B` was checked right away and needs `BA` to reach its target state
+    BPending(TA, BA),
+    /// `A` needs `AA` to reach its target state; `B` has not been checked yet
+    APending(AA, B),
+}
+
+impl<A, TA, AA, B, TB, BA, E> Ensure<(TA, TB)> for Sequence<A, B>
+where
+    A: Ensure<TA, EnsureAction = AA>,
+    AA: Meet<Met = TA, Error = E>,
+    B: Ensure<TB, EnsureAction = BA>,
+    BA: Meet<Met = TB, Error = E>,
+{
+    type EnsureAction = SequenceAction<TA, AA, B, BA>;
+
+    fn check_ensure(self) -> Result<CheckEnsureResult<(TA, TB), Self::EnsureAction>, E> {
+        match self.0.check_ensure()? {
+            CheckEnsureResult::Met(ta) => match self.1.check_ensure()? {
+                CheckEnsureResult::Met(tb) => Ok(CheckEnsureResult::Met((ta, tb))),
+                CheckEnsureResult::EnsureAction(action) => Ok(CheckEnsureResult::EnsureAction(SequenceAction::BPending(ta, action))),
+            },
+            CheckEnsureResult::EnsureAction(action) => Ok(CheckEnsureResult::EnsureAction(SequenceAction::APending(action, self.1))),
+        }
+    }
+}
+
+impl<TA, AA, B, TB, BA, E> Meet for SequenceAction<TA, AA, B, BA>
+where AA: Meet<Met = TA, Error = E>, B: Ensure<TB, EnsureAction = BA>, BA: Meet<Met = TB, Error = E> {
+    type Met = (TA, TB);
+    type Error = E;
+
+    fn meet(self) -> Result<(TA, TB), E> {
+        match self {
+            SequenceAction::BPending(ta, action) => Ok((ta, action.meet()?)),
+            SequenceAction::APending(action, b) => {
+                let ta = action.meet()?;
+                let tb = b.ensure()?;
+                Ok((ta, tb))
+            }
+        }
+    }
+}
+
+impl<TA, AA, B, BA> SequenceAction<TA, AA, B, BA> {
+    /// The constituent `EnsureAction`s that are already known to be pending, for inspection
+    /// before `meet()` runs. `B`'s action is only known once `A` is already `Met` -- if `A`
+    /// itself still needs to run, `B` has not been checked yet and cannot be reported here.
+    pub fn pending_actions(&self) -> Vec<&dyn Debug> where AA: Debug, BA: Debug {
+        match self {
+            SequenceAction::BPending(_, action) => vec![action as &dyn Debug],
+            SequenceAction::APending(action, _) => vec![action as &dyn Debug],
+        }
+    }
+}
+
+/// Ensures `A`, and only if `A` is already `Met` at `check_ensure()` time also ensures `B`.
+///
+/// If `A` is not met, `B` is skipped entirely rather than ensured, mirroring the
+/// target-reached/execute split used by host-configuration tooling (e.g. an `onlyif` guard).
+pub struct IfMet<A, B>(pub A, pub B);
+
+/// `EnsureAction` for `IfMet<A, B>`.
+#[derive(Debug)]
+pub enum IfMetAction<TA, AA, TB, BA> {
+    OnlyA(AA, std::marker::PhantomData<TB>),
+    Both(TA, BA),
+}
+
+impl<TA, AA, TB, BA> IfMetAction<TA, AA, TB, BA> {
+    /// The constituent `EnsureAction`s that are pending, for inspection before `meet()` runs.
+    /// Only ever contains `A`'s action when `B` was skipped entirely because `A` was not `Met`.
+    pub fn pending_actions(&self) -> Vec<&dyn Debug> where AA: Debug, BA: Debug {
+        match self {
+            IfMetAction::OnlyA(action, _) => vec![action as &dyn Debug],
+            IfMetAction::Both(_, action) => vec![action as &dyn Debug],
+        }
+    }
+}
+
+impl<A, TA, AA, B, TB, BA, E> Ensure<(TA, Option<TB>)> for IfMet<A, B>
+where
+    A: Ensure<TA, EnsureAction = AA>,
+    AA: Meet<Met = TA, Error = E>,
+    B: Ensure<TB, EnsureAction = BA>,
+    BA: Meet<Met = TB, Error = E>,
+{
+    type EnsureAction = IfMetAction<TA, AA, TB, BA>;
+
+    fn check_ensure(self) -> Result<CheckEnsureResult<(TA, Option<TB>), Self::EnsureAction>, E> {
+        match self.0.check_ensure()? {
+            CheckEnsureResult::Met(ta) => match self.1.check_ensure()? {
+                CheckEnsureResult::Met(tb) => Ok(CheckEnsureResult::Met((ta, Some(tb)))),
+                CheckEnsureResult::EnsureAction(action) => Ok(CheckEnsureResult::EnsureAction(IfMetAction::Both(ta, action))),
+            },
+            CheckEnsureResult::EnsureAction(action) => Ok(CheckEnsureResult::EnsureAction(IfMetAction::OnlyA(action, std::marker::PhantomData))),
+        }
+    }
+}
+
+impl<TA, AA, TB, BA, E> Meet for IfMetAction<TA, AA, TB, BA>
+where AA: Meet<Met = TA, Error = E>, BA: Meet<Met = TB, Error = E> {
+    type Met = (TA, Option<TB>);
+    type Error = E;
+
+    fn meet(self) -> Result<(TA, Option<TB>), E> {
+        match self {
+            IfMetAction::OnlyA(action, _) => Ok((action.meet()?, None)),
+            IfMetAction::Both(ta, action) => Ok((ta, Some(action.meet()?))),
+        }
+    }
+}
+
+/// Ensures every item of a homogeneous iterator of `Ensure`s, producing a `Vec` of their `Met` values.
+pub struct All<I>(pub I);
+
+/// `EnsureAction` for `All<I>`.
+#[derive(Debug)]
+pub struct AllAction<T, A>(Vec<CheckEnsureResult<T, A>>);
+
+impl<T, A> AllAction<T, A> {
+    /// The constituent `EnsureAction`s that are pending, for inspection before `meet()` runs.
+    pub fn pending_actions(&self) -> Vec<&dyn Debug> where A: Debug {
+        self.0.iter().filter_map(|result| result.as_ref().action()).map(|action| action as &dyn Debug).collect()
+    }
+}
+
+impl<I, R, T, A, E> Ensure<Vec<T>> for All<I>
+where
+    I: IntoIterator<Item = R>,
+    R: Ensure<T, EnsureAction = A>,
+    A: Meet<Met = T, Error = E>,
+{
+    type EnsureAction = AllAction<T, A>;
+
+    fn check_ensure(self) -> Result<CheckEnsureResult<Vec<T>, Self::EnsureAction>, E> {
+        let results = self.0.into_iter().map(|item| item.check_ensure()).collect::<Result<Vec<_>, E>>()?;
+
+        if results.iter().any(|result| matches!(result, CheckEnsureResult::EnsureAction(_))) {
+            Ok(CheckEnsureResult::EnsureAction(AllAction(results)))
+        } else {
+            Ok(CheckEnsureResult::Met(results.into_iter().map(|result| match result {
+                CheckEnsureResult::Met(met) => met,
+                CheckEnsureResult::EnsureAction(_) => unreachable!("checked above that no result is an EnsureAction"),
+            }).collect()))
+        }
+    }
+}
+
+impl<T, A, E> Meet for AllAction<T, A> where A: Meet<Met = T, Error = E> {
+    type Met = Vec<T>;
+    type Error = E;
+
+    fn meet(self) -> Result<Vec<T>, E> {
+        self.0.into_iter().map(|result| match result {
+            CheckEnsureResult::Met(met) => Ok(met),
+            CheckEnsureResult::EnsureAction(action) => action.meet(),
+        }).collect()
+    }
+}
+
+/// Mark `T` as something whose existence could not be determined without side effects.
+pub struct Indeterminate<T>(pub T);
+
+impl<T> Deref for Indeterminate<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> Debug for Indeterminate<T> where T: Debug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Indeterminate")
+            .field(&self.0)
+            .finish()
+    }
+}
+
+impl<T> PartialEq for Indeterminate<T> where T: PartialEq {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T> PartialOrd for Indeterminate<T> where T: PartialOrd {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+/// Tri-state result of `Existential::check_existence()`: `T` is known to be `Present`, known to
+/// be `Absent`, or its existence is `Unknown` without performing a side-effecting check.
+#[derive(Debug)]
+pub enum Existence<T> {
+    Present(Present<T>),
+    Absent(Absent<T>),
+    Unknown(Indeterminate<T>),
+}
+
+impl<T> Existence<T> {
+    pub fn is_present(&self) -> bool {
+        matches!(self, Existence::Present(_))
+    }
+
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Existence::Absent(_))
+    }
+
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Existence::Unknown(_))
+    }
+}
+
 /// Types implement `Existential` trait if they implement both `Ensure<Present<T>>` and `Ensure<Absent<T>>`.
 pub trait Existential<T> {
     type Error;
@@ -202,6 +582,29 @@ pub trait Existential<T> {
     fn ensure_present(self) -> Result<Present<T>, Self::Error>;
     /// Ensure that `T` is `Absent<T>`
     fn ensure_absent(self) -> Result<Absent<T>, Self::Error>;
+
+    /// Cheaply check whether `T` is currently `Present`, `Absent`, or its existence cannot be
+    /// determined without running an `EnsureAction`, by calling `check_ensure()` for `Present<T>`
+    /// and, only if that is not `Met`, for `Absent<T>`, without ever calling `meet()`.
+    fn check_existence(self) -> Result<Existence<T>, Self::Error>
+    where
+        Self: Clone + Into<T>,
+        Self: Ensure<Present<T>>,
+        Self: Ensure<Absent<T>>,
+        <Self as Ensure<Present<T>>>::EnsureAction: Meet<Met = Present<T>, Error = Self::Error>,
+        <Self as Ensure<Absent<T>>>::EnsureAction: Meet<Met = Absent<T>, Error = Self::Error>,
+    {
+        let indeterminate = self.clone();
+
+        if let CheckEnsureResult::Met(present) = <Self as Ensure<Present<T>>>::check_ensure(self.clone())? {
+            return Ok(Existence::Present(present));
+        }
+
+        match <Self as Ensure<Absent<T>>>::check_ensure(self)? {
+            CheckEnsureResult::Met(absent) => Ok(Existence::Absent(absent)),
+            CheckEnsureResult::EnsureAction(_) => Ok(Existence::Unknown(Indeterminate(indeterminate.into()))),
+        }
+    }
 }
 
 impl<T, E, R, PA, AA> Existential<T> for R where
@@ -241,6 +644,7 @@ mod test {
         assert_eq!(ensure(test(false)), Ok(2));
     }
 
+    #[derive(Clone)]
     struct Resource;
 
     struct CreateResourceAction(Resource);
@@ -298,4 +702,228 @@ mod test {
         let _r: Result<Present<Resource>, ()> = Resource.ensure_present();
         let _r: Result<Absent<Resource>, ()> = Resource.ensure_absent();
     }
+
+    #[derive(Clone)]
+    struct Probe(bool, bool);
+
+    struct ProbeAction<M>(M);
+    impl<M> Meet for ProbeAction<M> {
+        type Met = M;
+        type Error = ();
+
+        fn meet(self) -> Result<M, ()> {
+            Ok(self.0)
+        }
+    }
+
+    impl Ensure<Present<Probe>> for Probe {
+        type EnsureAction = ProbeAction<Present<Probe>>;
+
+        fn check_ensure(self) -> Result<CheckEnsureResult<Present<Probe>, Self::EnsureAction>, ()> {
+            Ok(if self.0 {
+                Met(Present(self))
+            } else {
+                EnsureAction(ProbeAction(Present(self)))
+            })
+        }
+    }
+
+    impl Ensure<Absent<Probe>> for Probe {
+        type EnsureAction = ProbeAction<Absent<Probe>>;
+
+        fn check_ensure(self) -> Result<CheckEnsureResult<Absent<Probe>, Self::EnsureAction>, ()> {
+            Ok(if self.1 {
+                Met(Absent(self))
+            } else {
+                EnsureAction(ProbeAction(Absent(self)))
+            })
+        }
+    }
+
+    #[test]
+    fn test_check_existence() {
+        assert!(Probe(true, false).check_existence().unwrap().is_present());
+        assert!(Probe(false, true).check_existence().unwrap().is_absent());
+        assert!(Probe(false, false).check_existence().unwrap().is_unknown());
+    }
+
+    fn check(met: bool, value: u8) -> impl Ensure<u8, EnsureAction = impl Meet<Met = u8, Error = ()>> {
+        move || {
+            Ok(match met {
+                true => Met(value),
+                _ => EnsureAction(move || Ok(value)),
+            })
+        }
+    }
+
+    #[test]
+    fn test_check_ensure_result_combinators() {
+        let met = || -> CheckEnsureResult<u8, u8> { Met(1) };
+        let action = || -> CheckEnsureResult<u8, u8> { EnsureAction(2) };
+
+        assert!(met().is_met());
+        assert!(!met().is_action());
+        assert!(!action().is_met());
+        assert!(action().is_action());
+
+        assert_eq!(met().met(), Some(1));
+        assert_eq!(action().met(), None);
+        assert_eq!(met().action(), None);
+        assert_eq!(action().action(), Some(2));
+
+        assert!(matches!(met().map_met(|m| m + 1), Met(2)));
+        assert!(matches!(action().map_met(|m| m + 1), EnsureAction(2)));
+        assert!(matches!(met().map_action(|a| a + 1), Met(1)));
+        assert!(matches!(action().map_action(|a| a + 1), EnsureAction(3)));
+
+        assert_eq!(met().met_or_else(|a| a + 1), 1);
+        assert_eq!(action().met_or_else(|a| a + 1), 3);
+
+        assert!(matches!(met().as_ref(), Met(&1)));
+        assert!(matches!(action().as_ref(), EnsureAction(&2)));
+
+        let mut m = met();
+        if let Met(v) = m.as_mut() {
+            *v += 1;
+        }
+        assert!(matches!(m, Met(2)));
+    }
+
+    #[test]
+    fn test_plan() {
+        let nothing = check(true, 1).plan().unwrap();
+        assert!(!nothing.is_pending());
+        assert_eq!(nothing.apply(), Ok(1));
+
+        let pending = check(false, 2).plan().unwrap();
+        assert!(pending.is_pending());
+        assert_eq!(pending.apply(), Ok(2));
+    }
+
+    #[test]
+    fn test_ensure_report() {
+        let met = check(true, 1).ensure_report().unwrap();
+        assert_eq!(met, Ensured::AlreadyMet(1));
+        assert!(!met.was_changed());
+        assert_eq!(met.into_inner(), 1);
+
+        let ensured = check(false, 2).ensure_report().unwrap();
+        assert_eq!(ensured, Ensured::Ensured(2));
+        assert!(ensured.was_changed());
+        assert_eq!(ensured.into_inner(), 2);
+    }
+
+    #[test]
+    fn test_sequence() {
+        assert_eq!(Sequence(check(true, 1), check(true, 2)).ensure(), Ok((1, 2)));
+        assert_eq!(Sequence(check(false, 1), check(true, 2)).ensure(), Ok((1, 2)));
+        assert_eq!(Sequence(check(true, 1), check(false, 2)).ensure(), Ok((1, 2)));
+        assert_eq!(Sequence(check(false, 1), check(false, 2)).ensure(), Ok((1, 2)));
+    }
+
+    #[test]
+    fn test_if_met() {
+        assert_eq!(IfMet(check(true, 1), check(true, 2)).ensure(), Ok((1, Some(2))));
+        assert_eq!(IfMet(check(true, 1), check(false, 2)).ensure(), Ok((1, Some(2))));
+        assert_eq!(IfMet(check(false, 1), check(true, 2)).ensure(), Ok((1, None)));
+    }
+
+    // `Dir`/`File` model "ensure the directory exists, and only then ensure the file inside it":
+    // `File::check_ensure()` asserts its prerequisite directory already exists, so checking it
+    // before `Dir`'s `meet()` has run (the bug this guards against) fails the test immediately.
+    struct Dir(std::rc::Rc<std::cell::Cell<bool>>);
+    struct DirAction(std::rc::Rc<std::cell::Cell<bool>>);
+    impl Meet for DirAction {
+        type Met = ();
+        type Error = ();
+
+        fn meet(self) -> Result<(), ()> {
+            self.0.set(true);
+            Ok(())
+        }
+    }
+    impl Ensure<()> for Dir {
+        type EnsureAction = DirAction;
+
+        fn check_ensure(self) -> Result<CheckEnsureResult<(), Self::EnsureAction>, ()> {
+            Ok(if self.0.get() { Met(()) } else { EnsureAction(DirAction(self.0)) })
+        }
+    }
+
+    struct File(std::rc::Rc<std::cell::Cell<bool>>);
+    struct FileAction;
+    impl Meet for FileAction {
+        type Met = ();
+        type Error = ();
+
+        fn meet(self) -> Result<(), ()> {
+            Ok(())
+        }
+    }
+    impl Ensure<()> for File {
+        type EnsureAction = FileAction;
+
+        fn check_ensure(self) -> Result<CheckEnsureResult<(), Self::EnsureAction>, ()> {
+            assert!(self.0.get(), "file checked before its directory prerequisite existed");
+            Ok(EnsureAction(FileAction))
+        }
+    }
+
+    #[test]
+    fn test_sequence_prerequisite() {
+        let dir_exists = std::rc::Rc::new(std::cell::Cell::new(false));
+        assert_eq!(Sequence(Dir(dir_exists.clone()), File(dir_exists)).ensure(), Ok(((), ())));
+    }
+
+    #[test]
+    fn test_if_met_prerequisite() {
+        let dir_exists = std::rc::Rc::new(std::cell::Cell::new(true));
+        assert_eq!(IfMet(Dir(dir_exists.clone()), File(dir_exists)).ensure(), Ok(((), Some(()))));
+
+        let dir_missing = std::rc::Rc::new(std::cell::Cell::new(false));
+        assert_eq!(IfMet(Dir(dir_missing.clone()), File(dir_missing)).ensure(), Ok(((), None)));
+    }
+
+    #[test]
+    fn test_all() {
+        assert_eq!(All(vec![check(true, 1), check(true, 2)]).ensure(), Ok(vec![1, 2]));
+        assert_eq!(All(vec![check(true, 1), check(false, 2)]).ensure(), Ok(vec![1, 2]));
+        assert_eq!(All(vec![check(false, 1), check(false, 2)]).ensure(), Ok(vec![1, 2]));
+    }
+
+    // `check()` returns an opaque closure `EnsureAction` which does not implement `Debug`;
+    // `pending_actions()` needs `Debug` actions to be worth inspecting/printing, so use a named
+    // type here instead.
+    #[derive(Debug)]
+    struct ValueAction(u8);
+    impl Meet for ValueAction {
+        type Met = u8;
+        type Error = ();
+
+        fn meet(self) -> Result<u8, ()> {
+            Ok(self.0)
+        }
+    }
+
+    fn checked(met: bool, value: u8) -> impl Ensure<u8, EnsureAction = ValueAction> {
+        move || Ok(if met { Met(value) } else { EnsureAction(ValueAction(value)) })
+    }
+
+    #[test]
+    fn test_pending_actions() {
+        match All(vec![checked(true, 1), checked(false, 2)]).plan().unwrap() {
+            Plan::Pending(action) => assert_eq!(action.pending_actions().len(), 1),
+            Plan::Nothing(_) => panic!("expected a pending action"),
+        }
+
+        match Sequence(checked(false, 1), checked(false, 2)).plan().unwrap() {
+            Plan::Pending(action) => assert_eq!(action.pending_actions().len(), 1),
+            Plan::Nothing(_) => panic!("expected a pending action"),
+        }
+
+        match IfMet(checked(true, 1), checked(false, 2)).plan().unwrap() {
+            Plan::Pending(action) => assert_eq!(action.pending_actions().len(), 1),
+            Plan::Nothing(_) => panic!("expected a pending action"),
+        }
+    }
 }